@@ -0,0 +1,217 @@
+use crate::{
+    term::Term,
+    typing::{Type, TypeError, type_of},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CodegenError {
+    TypeError(TypeError),
+}
+
+impl From<TypeError> for CodegenError {
+    fn from(error: TypeError) -> CodegenError {
+        CodegenError::TypeError(error)
+    }
+}
+
+/// Lower a type-checked `Term` into an LLVM IR module whose `@main` returns the
+/// value of the program. The term must type-check first so both arms of a
+/// conditional share a concrete LLVM type for the merging `phi`.
+pub fn compile(term: &Term) -> Result<String, CodegenError> {
+    let ret = llvm_type(term)?;
+    let mut codegen = Codegen::default();
+    codegen.start_block("entry".to_string());
+    let value = codegen.lower(term)?;
+    let mut module = format!("define {ret} @main() {{\n");
+    for line in codegen.lines {
+        module.push_str(&line);
+        module.push('\n');
+    }
+    module.push_str(&format!("  ret {ret} {value}\n}}\n"));
+    Ok(module)
+}
+
+#[derive(Default)]
+struct Codegen {
+    counter: usize,
+    lines: Vec<String>,
+    block: String,
+}
+
+impl Codegen {
+    fn fresh(&mut self) -> String {
+        self.counter += 1;
+        format!("%t{}", self.counter)
+    }
+
+    fn fresh_label(&mut self) -> String {
+        self.counter += 1;
+        format!("bb{}", self.counter)
+    }
+
+    fn emit(&mut self, instruction: String) {
+        self.lines.push(format!("  {instruction}"));
+    }
+
+    fn start_block(&mut self, label: String) {
+        self.lines.push(format!("{label}:"));
+        self.block = label;
+    }
+
+    fn lower(&mut self, term: &Term) -> Result<String, CodegenError> {
+        match term {
+            Term::True => Ok("1".to_string()),
+            Term::False => Ok("0".to_string()),
+            Term::Number { value } => Ok(value.to_string()),
+            Term::Float { value } => Ok(format_float(*value)),
+            Term::Negation { operand } => {
+                let ty = llvm_type(term)?;
+                let operand = self.lower(operand)?;
+                let result = self.fresh();
+                if ty == "double" {
+                    self.emit(format!("{result} = fneg double {operand}"));
+                } else {
+                    self.emit(format!("{result} = sub i64 0, {operand}"));
+                }
+                Ok(result)
+            }
+            Term::Addition { left, right }
+            | Term::Subtraction { left, right }
+            | Term::Multiplication { left, right }
+            | Term::Division { left, right } => {
+                let ty = llvm_type(term)?;
+                let mut operands = Vec::new();
+                for operand in [left, right] {
+                    let value = self.lower(operand)?;
+                    // A `Nat` operand of a `Float` operation is promoted.
+                    if ty == "double" && llvm_type(operand)? == "i64" {
+                        let converted = self.fresh();
+                        self.emit(format!("{converted} = sitofp i64 {value} to double"));
+                        operands.push(converted);
+                    } else {
+                        operands.push(value);
+                    }
+                }
+                let (left, right) = (&operands[0], &operands[1]);
+                let result = self.fresh();
+                let op = binary_op(term, ty == "double");
+                self.emit(format!("{result} = {op} {ty} {left}, {right}"));
+                Ok(result)
+            }
+            Term::Condition {
+                condition,
+                consequent,
+                alternative,
+            } => {
+                let ty = llvm_type(term)?;
+                let condition = self.lower(condition)?;
+                let then_label = self.fresh_label();
+                let else_label = self.fresh_label();
+                let merge_label = self.fresh_label();
+                self.emit(format!(
+                    "br i1 {condition}, label %{then_label}, label %{else_label}"
+                ));
+
+                self.start_block(then_label);
+                let consequent = self.lower(consequent)?;
+                let then_end = self.block.clone();
+                self.emit(format!("br label %{merge_label}"));
+
+                self.start_block(else_label);
+                let alternative = self.lower(alternative)?;
+                let else_end = self.block.clone();
+                self.emit(format!("br label %{merge_label}"));
+
+                self.start_block(merge_label);
+                let result = self.fresh();
+                self.emit(format!(
+                    "{result} = phi {ty} [ {consequent}, %{then_end} ], [ {alternative}, %{else_end} ]"
+                ));
+                Ok(result)
+            }
+        }
+    }
+}
+
+fn binary_op(term: &Term, float: bool) -> &'static str {
+    match term {
+        Term::Addition { .. } => {
+            if float {
+                "fadd"
+            } else {
+                "add"
+            }
+        }
+        Term::Subtraction { .. } => {
+            if float {
+                "fsub"
+            } else {
+                "sub"
+            }
+        }
+        Term::Multiplication { .. } => {
+            if float {
+                "fmul"
+            } else {
+                "mul"
+            }
+        }
+        Term::Division { .. } => {
+            if float {
+                "fdiv"
+            } else {
+                "sdiv"
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn llvm_type(term: &Term) -> Result<&'static str, CodegenError> {
+    Ok(match type_of(term)? {
+        Type::Bool => "i1",
+        Type::Nat => "i64",
+        Type::Float => "double",
+    })
+}
+
+fn format_float(value: f64) -> String {
+    if value == value.trunc() {
+        format!("{value:.1}")
+    } else {
+        format!("{value}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{codegen::compile, parser::parse};
+
+    #[test]
+    fn test_addition() {
+        assert_eq!(
+            compile(&parse("1 + 2").unwrap()).unwrap(),
+            "define i64 @main() {\nentry:\n  %t1 = add i64 1, 2\n  ret i64 %t1\n}\n",
+        );
+    }
+
+    #[test]
+    fn test_condition() {
+        assert_eq!(
+            compile(&parse("true ? 1 : 2").unwrap()).unwrap(),
+            concat!(
+                "define i64 @main() {\n",
+                "entry:\n",
+                "  br i1 1, label %bb1, label %bb2\n",
+                "bb1:\n",
+                "  br label %bb3\n",
+                "bb2:\n",
+                "  br label %bb3\n",
+                "bb3:\n",
+                "  %t4 = phi i64 [ 1, %bb1 ], [ 2, %bb2 ]\n",
+                "  ret i64 %t4\n",
+                "}\n",
+            ),
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use crate::term::Term;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    Stuck(Term),
+}
+
+fn is_value(term: &Term) -> bool {
+    matches!(
+        term,
+        Term::True | Term::False | Term::Number { .. } | Term::Float { .. }
+    )
+}
+
+pub fn step(term: &Term) -> Option<Term> {
+    match term {
+        Term::Condition {
+            condition,
+            consequent,
+            alternative,
+        } => match **condition {
+            Term::True => Some((**consequent).clone()),
+            Term::False => Some((**alternative).clone()),
+            _ => step(condition).map(|condition| Term::Condition {
+                condition: Box::new(condition),
+                consequent: consequent.clone(),
+                alternative: alternative.clone(),
+            }),
+        },
+        Term::Addition { left, right }
+        | Term::Subtraction { left, right }
+        | Term::Multiplication { left, right }
+        | Term::Division { left, right } => {
+            if !is_value(left) {
+                step(left).map(|left| rebuild(term, left, (**right).clone()))
+            } else if !is_value(right) {
+                step(right).map(|right| rebuild(term, (**left).clone(), right))
+            } else {
+                apply(term, left, right)
+            }
+        }
+        Term::Negation { operand } => match &**operand {
+            Term::Number { value } => Some(Term::Number { value: -value }),
+            Term::Float { value } => Some(Term::Float { value: -value }),
+            _ if !is_value(operand) => step(operand).map(|operand| Term::Negation {
+                operand: Box::new(operand),
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn as_float(term: &Term) -> Option<f64> {
+    match term {
+        Term::Number { value } => Some(*value as f64),
+        Term::Float { value } => Some(*value),
+        _ => None,
+    }
+}
+
+fn rebuild(term: &Term, left: Term, right: Term) -> Term {
+    let left = Box::new(left);
+    let right = Box::new(right);
+    match term {
+        Term::Addition { .. } => Term::Addition { left, right },
+        Term::Subtraction { .. } => Term::Subtraction { left, right },
+        Term::Multiplication { .. } => Term::Multiplication { left, right },
+        Term::Division { .. } => Term::Division { left, right },
+        _ => unreachable!(),
+    }
+}
+
+fn apply(term: &Term, left: &Term, right: &Term) -> Option<Term> {
+    if let (Term::Number { value: left }, Term::Number { value: right }) = (left, right) {
+        let value = match term {
+            Term::Addition { .. } => left + right,
+            Term::Subtraction { .. } => left - right,
+            Term::Multiplication { .. } => left * right,
+            Term::Division { .. } if *right != 0 => left / right,
+            _ => return None,
+        };
+        return Some(Term::Number { value });
+    }
+    let (left, right) = (as_float(left)?, as_float(right)?);
+    let value = match term {
+        Term::Addition { .. } => left + right,
+        Term::Subtraction { .. } => left - right,
+        Term::Multiplication { .. } => left * right,
+        Term::Division { .. } => left / right,
+        _ => return None,
+    };
+    Some(Term::Float { value })
+}
+
+pub fn eval(term: &Term) -> Result<Term, EvalError> {
+    let mut term = term.clone();
+    while let Some(next) = step(&term) {
+        term = next;
+    }
+    if is_value(&term) {
+        Ok(term)
+    } else {
+        Err(EvalError::Stuck(term))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        eval::{EvalError, eval},
+        parser::parse,
+        term::Term,
+    };
+
+    #[test]
+    fn test_addition() {
+        assert_eq!(eval(&parse("1 + 2").unwrap()), Ok(Term::Number { value: 3 }));
+        assert_eq!(
+            eval(&parse("1 + 2 + 3").unwrap()),
+            Ok(Term::Number { value: 6 }),
+        );
+    }
+
+    #[test]
+    fn test_float_addition() {
+        assert_eq!(
+            eval(&parse("1 + 2.0").unwrap()),
+            Ok(Term::Float { value: 3.0 }),
+        );
+        assert_eq!(
+            eval(&parse("1.5 + 2.5").unwrap()),
+            Ok(Term::Float { value: 4.0 }),
+        );
+    }
+
+    #[test]
+    fn test_condition() {
+        assert_eq!(
+            eval(&parse("true ? 1 : 2").unwrap()),
+            Ok(Term::Number { value: 1 }),
+        );
+        assert_eq!(
+            eval(&parse("false ? 1 : 2").unwrap()),
+            Ok(Term::Number { value: 2 }),
+        );
+    }
+
+    #[test]
+    fn test_nested_condition() {
+        assert_eq!(
+            eval(&parse("(true ? false : true) ? 1 : 2").unwrap()),
+            Ok(Term::Number { value: 2 }),
+        );
+    }
+
+    #[test]
+    fn test_stuck() {
+        assert_eq!(
+            eval(&parse("true + 1").unwrap()),
+            Err(EvalError::Stuck(Term::Addition {
+                left: Box::new(Term::True),
+                right: Box::new(Term::Number { value: 1 }),
+            })),
+        );
+    }
+}
@@ -0,0 +1,6 @@
+#[cfg(feature = "llvm")]
+pub mod codegen;
+pub mod eval;
+pub mod parser;
+pub mod term;
+pub mod typing;
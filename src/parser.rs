@@ -1,29 +1,72 @@
 use crate::term::Term;
 use nom::{
-    IResult, Parser,
+    Err, IResult, Parser,
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char as nom_char, i64 as nom_i64, multispace0},
-    combinator::{eof, map, opt},
+    character::complete::{char as nom_char, digit1, i64 as nom_i64, multispace0},
+    combinator::{eof, map, map_res, opt, recognize},
     error::ParseError as NomParseError,
     multi::many0,
     sequence::delimited,
 };
+use std::ops::Range;
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct ParseError;
+pub struct ParseError {
+    pub message: String,
+    pub span: Range<usize>,
+}
+
+impl ParseError {
+    fn from_nom(input: &str, err: Err<nom::error::Error<&str>>) -> ParseError {
+        let rest = match err {
+            Err::Error(err) | Err::Failure(err) => err.input,
+            Err::Incomplete(_) => "",
+        };
+        let offset = input.len() - rest.len();
+        if rest.is_empty() {
+            ParseError {
+                message: "unexpected end of input".to_string(),
+                span: offset..offset,
+            }
+        } else {
+            let token = rest.split_whitespace().next().unwrap_or(rest);
+            ParseError {
+                message: format!("unexpected token `{token}`"),
+                span: offset..offset + token.len(),
+            }
+        }
+    }
+
+    /// Render the offending region as a caret-underlined snippet of `source`.
+    pub fn render(&self, source: &str) -> String {
+        let start = source[..self.span.start].rfind('\n').map_or(0, |index| index + 1);
+        let end = source[self.span.start..]
+            .find('\n')
+            .map_or(source.len(), |index| self.span.start + index);
+        let line = &source[start..end];
+        let column = self.span.start - start;
+        let width = (self.span.end - self.span.start).max(1);
+        format!(
+            "{message}\n{line}\n{caret}{underline}",
+            message = self.message,
+            caret = " ".repeat(column),
+            underline = "^".repeat(width),
+        )
+    }
+}
 
 pub fn parse(input: &str) -> Result<Term, ParseError> {
-    (expr, eof)
-        .parse(input)
-        .map(|(_, (term, _))| term)
-        .map_err(|_| ParseError)
+    match (expr, eof).parse(input) {
+        Ok((_, (term, _))) => Ok(term),
+        Err(err) => Err(ParseError::from_nom(input, err)),
+    }
 }
 
 fn expr(input: &str) -> IResult<&str, Term> {
     map(
         (
-            term,
+            additive,
             opt((
                 whitespace(nom_char('?')),
                 expr,
@@ -43,19 +86,68 @@ fn expr(input: &str) -> IResult<&str, Term> {
     .parse(input)
 }
 
-fn term(input: &str) -> IResult<&str, Term> {
+fn additive(input: &str) -> IResult<&str, Term> {
+    map(
+        (
+            multiplicative,
+            many0((
+                alt((whitespace(nom_char('+')), whitespace(nom_char('-')))),
+                multiplicative,
+            )),
+        ),
+        |(term, terms)| {
+            terms.into_iter().fold(term, |left, (op, right)| match op {
+                '+' => Term::Addition {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
+                _ => Term::Subtraction {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
+            })
+        },
+    )
+    .parse(input)
+}
+
+fn multiplicative(input: &str) -> IResult<&str, Term> {
     map(
-        (factor, many0((whitespace(nom_char('+')), term))),
+        (
+            unary,
+            many0((
+                alt((whitespace(nom_char('*')), whitespace(nom_char('/')))),
+                unary,
+            )),
+        ),
         |(term, terms)| {
-            terms.iter().fold(term, |left, (_, right)| Term::Addition {
-                left: Box::new(left),
-                right: Box::new(right.clone()),
+            terms.into_iter().fold(term, |left, (op, right)| match op {
+                '*' => Term::Multiplication {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
+                _ => Term::Division {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
             })
         },
     )
     .parse(input)
 }
 
+fn unary(input: &str) -> IResult<&str, Term> {
+    alt((
+        map((whitespace(nom_char('-')), unary), |(_, operand)| {
+            Term::Negation {
+                operand: Box::new(operand),
+            }
+        }),
+        factor,
+    ))
+    .parse(input)
+}
+
 fn factor(input: &str) -> IResult<&str, Term> {
     alt((
         number,
@@ -66,7 +158,32 @@ fn factor(input: &str) -> IResult<&str, Term> {
 }
 
 fn number(input: &str) -> IResult<&str, Term> {
-    whitespace(map(nom_i64, |value| Term::Number { value })).parse(input)
+    whitespace(alt((float, integer))).parse(input)
+}
+
+// A float must carry a decimal point, so `1` still reads as an integer while
+// `1.0` reads as a float. `1.` parses as the float `1.0`; a `.` directly after
+// digits is always taken by the float, so `1.x` reads as the float `1.0`
+// followed by `x` and never as the integer `1` followed by `.`.
+fn float(input: &str) -> IResult<&str, Term> {
+    map_res(
+        recognize((
+            digit1,
+            nom_char('.'),
+            opt(digit1),
+            opt((
+                alt((nom_char('e'), nom_char('E'))),
+                opt(alt((nom_char('+'), nom_char('-')))),
+                digit1,
+            )),
+        )),
+        |value: &str| value.parse::<f64>().map(|value| Term::Float { value }),
+    )
+    .parse(input)
+}
+
+fn integer(input: &str) -> IResult<&str, Term> {
+    map(nom_i64, |value| Term::Number { value }).parse(input)
 }
 
 fn boolean(input: &str) -> IResult<&str, Term> {
@@ -90,6 +207,29 @@ where
 mod tests {
     use crate::{parser::parse, term::Term};
 
+    #[test]
+    fn test_trailing_operator() {
+        let error = parse("1 + ").unwrap_err();
+        assert_eq!(error.message, "unexpected token `+`");
+        assert_eq!(error.span, 2..3);
+    }
+
+    #[test]
+    fn test_unclosed_paren() {
+        let error = parse("(1 + 2").unwrap_err();
+        assert_eq!(error.message, "unexpected end of input");
+        assert_eq!(error.span, 6..6);
+    }
+
+    #[test]
+    fn test_render() {
+        let error = parse("(1 + 2").unwrap_err();
+        assert_eq!(
+            error.render("(1 + 2"),
+            "unexpected end of input\n(1 + 2\n      ^",
+        );
+    }
+
     #[test]
     fn test_boolean() {
         assert_eq!(parse("true").unwrap(), Term::True);
@@ -102,6 +242,15 @@ mod tests {
         assert_eq!(parse("123").unwrap(), Term::Number { value: 123 });
     }
 
+    #[test]
+    fn test_float() {
+        assert_eq!(parse("1.0").unwrap(), Term::Float { value: 1.0 });
+        assert_eq!(parse("1.").unwrap(), Term::Float { value: 1.0 });
+        assert_eq!(parse("3.14").unwrap(), Term::Float { value: 3.14 });
+        assert!(parse("1e3").is_err());
+        assert_eq!(parse("1").unwrap(), Term::Number { value: 1 });
+    }
+
     #[test]
     fn test_addition() {
         assert_eq!(
@@ -127,6 +276,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_left_associative() {
+        assert_eq!(
+            parse("1 + 2 + 3").unwrap(),
+            Term::Addition {
+                left: Box::new(Term::Addition {
+                    left: Box::new(Term::Number { value: 1 }),
+                    right: Box::new(Term::Number { value: 2 }),
+                }),
+                right: Box::new(Term::Number { value: 3 }),
+            },
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(
+            parse("2 + 3 * 4").unwrap(),
+            Term::Addition {
+                left: Box::new(Term::Number { value: 2 }),
+                right: Box::new(Term::Multiplication {
+                    left: Box::new(Term::Number { value: 3 }),
+                    right: Box::new(Term::Number { value: 4 }),
+                }),
+            },
+        );
+    }
+
+    #[test]
+    fn test_subtraction_and_division() {
+        assert_eq!(
+            parse("10 - 2 - 3").unwrap(),
+            Term::Subtraction {
+                left: Box::new(Term::Subtraction {
+                    left: Box::new(Term::Number { value: 10 }),
+                    right: Box::new(Term::Number { value: 2 }),
+                }),
+                right: Box::new(Term::Number { value: 3 }),
+            },
+        );
+        assert_eq!(
+            parse("8 / 4 / 2").unwrap(),
+            Term::Division {
+                left: Box::new(Term::Division {
+                    left: Box::new(Term::Number { value: 8 }),
+                    right: Box::new(Term::Number { value: 4 }),
+                }),
+                right: Box::new(Term::Number { value: 2 }),
+            },
+        );
+    }
+
+    #[test]
+    fn test_negation() {
+        assert_eq!(
+            parse("-1 + 2").unwrap(),
+            Term::Addition {
+                left: Box::new(Term::Negation {
+                    operand: Box::new(Term::Number { value: 1 }),
+                }),
+                right: Box::new(Term::Number { value: 2 }),
+            },
+        );
+    }
+
     #[test]
     fn test_condition() {
         assert_eq!(
@@ -1,3 +1,5 @@
+use std::fmt::{self, Display};
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Term {
     True,
@@ -10,8 +12,146 @@ pub enum Term {
     Number {
         value: i64,
     },
+    Float {
+        value: f64,
+    },
     Addition {
         left: Box<Term>,
         right: Box<Term>,
     },
+    Subtraction {
+        left: Box<Term>,
+        right: Box<Term>,
+    },
+    Multiplication {
+        left: Box<Term>,
+        right: Box<Term>,
+    },
+    Division {
+        left: Box<Term>,
+        right: Box<Term>,
+    },
+    Negation {
+        operand: Box<Term>,
+    },
+}
+
+// Binding strength of each term, matching the parser's layering (the parser's
+// combinators do not read this table): conditional is loosest, then additive,
+// multiplicative, unary negation, and finally atoms.
+// A child is parenthesized only when its precedence is below the context's.
+fn precedence(term: &Term) -> u8 {
+    match term {
+        Term::Condition { .. } => 0,
+        Term::Addition { .. } | Term::Subtraction { .. } => 1,
+        Term::Multiplication { .. } | Term::Division { .. } => 2,
+        Term::Negation { .. } => 3,
+        Term::True | Term::False | Term::Number { .. } | Term::Float { .. } => 4,
+    }
+}
+
+impl Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_term(self, f, 0)
+    }
+}
+
+fn write_term(term: &Term, f: &mut fmt::Formatter<'_>, min: u8) -> fmt::Result {
+    if precedence(term) < min {
+        write!(f, "(")?;
+        write_inner(term, f)?;
+        write!(f, ")")
+    } else {
+        write_inner(term, f)
+    }
+}
+
+fn write_inner(term: &Term, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match term {
+        Term::True => write!(f, "true"),
+        Term::False => write!(f, "false"),
+        Term::Number { value } => write!(f, "{value}"),
+        Term::Float { value } => write!(f, "{}", format_float(*value)),
+        Term::Negation { operand } => {
+            write!(f, "-")?;
+            write_term(operand, f, 3)
+        }
+        Term::Condition {
+            condition,
+            consequent,
+            alternative,
+        } => {
+            write_term(condition, f, 1)?;
+            write!(f, " ? ")?;
+            write_term(consequent, f, 0)?;
+            write!(f, " : ")?;
+            write_term(alternative, f, 0)
+        }
+        _ => {
+            let (left, right, op, level) = match term {
+                Term::Addition { left, right } => (left, right, '+', 1),
+                Term::Subtraction { left, right } => (left, right, '-', 1),
+                Term::Multiplication { left, right } => (left, right, '*', 2),
+                Term::Division { left, right } => (left, right, '/', 2),
+                _ => unreachable!(),
+            };
+            // Left-associative: the left child may share this level without
+            // parentheses, the right child must bind strictly tighter.
+            write_term(left, f, level)?;
+            write!(f, " {op} ")?;
+            write_term(right, f, level + 1)
+        }
+    }
+}
+
+// Finite floats always carry a decimal point so the result re-parses as a
+// `Float` rather than an integer `Number`; infinities and NaN print as `inf`
+// and `NaN`.
+fn format_float(value: f64) -> String {
+    if value.is_finite() && value == value.trunc() {
+        format!("{value:.1}")
+    } else {
+        format!("{value}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parser::parse, term::Term};
+
+    fn roundtrip(source: &str) {
+        let term = parse(source).unwrap();
+        assert_eq!(parse(&term.to_string()).unwrap(), term);
+    }
+
+    #[test]
+    fn test_minimal_parens() {
+        assert_eq!(parse("(1 + 2) + 3").unwrap().to_string(), "1 + 2 + 3");
+        assert_eq!(parse("1 + (2 + 3)").unwrap().to_string(), "1 + (2 + 3)");
+        assert_eq!(
+            parse("1 + (true ? 2 : 3)").unwrap().to_string(),
+            "1 + (true ? 2 : 3)",
+        );
+        assert_eq!(parse("2 + 3 * 4").unwrap().to_string(), "2 + 3 * 4");
+        assert_eq!(parse("(2 + 3) * 4").unwrap().to_string(), "(2 + 3) * 4");
+        assert_eq!(parse("-(1 + 2)").unwrap().to_string(), "-(1 + 2)");
+    }
+
+    #[test]
+    fn test_float_display() {
+        assert_eq!(Term::Float { value: 1.0 }.to_string(), "1.0");
+        assert_eq!(Term::Float { value: 3.14 }.to_string(), "3.14");
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        roundtrip("1 + 2 + 3");
+        roundtrip("1 + (2 + 3)");
+        roundtrip("2 + 3 * 4 - 5 / 6");
+        roundtrip("-1 + -2");
+        roundtrip("true ? 1 : 2");
+        roundtrip("1 + (true ? 2 : 3)");
+        roundtrip("(true ? false : true) ? 1 : 2");
+        roundtrip("1.5 + 2.0");
+    }
 }
@@ -0,0 +1,122 @@
+use crate::term::Term;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Bool,
+    Nat,
+    Float,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeError {
+    ExpectedNat,
+    GuardNotBool,
+    BranchMismatch {
+        consequent: Type,
+        alternative: Type,
+    },
+}
+
+pub fn type_of(term: &Term) -> Result<Type, TypeError> {
+    match term {
+        Term::True | Term::False => Ok(Type::Bool),
+        Term::Number { .. } => Ok(Type::Nat),
+        Term::Float { .. } => Ok(Type::Float),
+        Term::Addition { left, right }
+        | Term::Subtraction { left, right }
+        | Term::Multiplication { left, right }
+        | Term::Division { left, right } => match (type_of(left)?, type_of(right)?) {
+            // `Nat` promotes to `Float` whenever either operand is a float.
+            (Type::Nat, Type::Nat) => Ok(Type::Nat),
+            (Type::Nat | Type::Float, Type::Nat | Type::Float) => Ok(Type::Float),
+            _ => Err(TypeError::ExpectedNat),
+        },
+        Term::Negation { operand } => match type_of(operand)? {
+            Type::Nat => Ok(Type::Nat),
+            Type::Float => Ok(Type::Float),
+            _ => Err(TypeError::ExpectedNat),
+        },
+        Term::Condition {
+            condition,
+            consequent,
+            alternative,
+        } => {
+            if type_of(condition)? != Type::Bool {
+                return Err(TypeError::GuardNotBool);
+            }
+            let consequent = type_of(consequent)?;
+            let alternative = type_of(alternative)?;
+            if consequent == alternative {
+                Ok(consequent)
+            } else {
+                Err(TypeError::BranchMismatch {
+                    consequent,
+                    alternative,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parser::parse,
+        typing::{Type, TypeError, type_of},
+    };
+
+    #[test]
+    fn test_number() {
+        assert_eq!(type_of(&parse("1").unwrap()), Ok(Type::Nat));
+        assert_eq!(type_of(&parse("1 + 2").unwrap()), Ok(Type::Nat));
+    }
+
+    #[test]
+    fn test_boolean() {
+        assert_eq!(type_of(&parse("true").unwrap()), Ok(Type::Bool));
+        assert_eq!(type_of(&parse("false").unwrap()), Ok(Type::Bool));
+    }
+
+    #[test]
+    fn test_condition() {
+        assert_eq!(type_of(&parse("true ? 1 : 2").unwrap()), Ok(Type::Nat));
+        assert_eq!(
+            type_of(&parse("true ? false : true").unwrap()),
+            Ok(Type::Bool),
+        );
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(type_of(&parse("1.0").unwrap()), Ok(Type::Float));
+        assert_eq!(type_of(&parse("1 + 2.0").unwrap()), Ok(Type::Float));
+        assert_eq!(type_of(&parse("1.5 + 2.5").unwrap()), Ok(Type::Float));
+    }
+
+    #[test]
+    fn test_expected_nat() {
+        assert_eq!(
+            type_of(&parse("true + 1").unwrap()),
+            Err(TypeError::ExpectedNat),
+        );
+    }
+
+    #[test]
+    fn test_guard_not_bool() {
+        assert_eq!(
+            type_of(&parse("1 ? 2 : 3").unwrap()),
+            Err(TypeError::GuardNotBool),
+        );
+    }
+
+    #[test]
+    fn test_branch_mismatch() {
+        assert_eq!(
+            type_of(&parse("true ? 1 : false").unwrap()),
+            Err(TypeError::BranchMismatch {
+                consequent: Type::Nat,
+                alternative: Type::Bool,
+            }),
+        );
+    }
+}